@@ -1,11 +1,12 @@
 use arboard::Clipboard;
 use clap::Parser;
 use content_inspector::{inspect, ContentType};
-use glob::Pattern;
-use ignore::Walk;
-use std::collections::{HashSet, VecDeque};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use ignore::WalkBuilder;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::thread::sleep;
 use std::time::Duration;
 
@@ -37,6 +38,37 @@ struct Args {
     /// Additional files or directories to ignore (supports glob patterns)
     #[arg(short = 'i', long = "ignore", value_delimiter = ',')]
     ignore: Vec<String>,
+
+    /// Don't respect .gitignore files
+    #[arg(long = "no-ignore")]
+    no_ignore: bool,
+
+    /// Don't apply the built-in default ignore list
+    #[arg(long = "no-default-ignore")]
+    no_default_ignore: bool,
+}
+
+/// Name of the project-local ignore file, loaded from the target `path` before CLI
+/// `--ignore` patterns.
+const CPFSIGNORE_FILE: &str = ".cpfsignore";
+
+/// Loads patterns from a `.cpfsignore` file at the root of `base_path`, if one
+/// exists. One pattern per line; blank lines and lines starting with `#` are
+/// skipped, and `!` negation is supported exactly as for CLI `--ignore` patterns.
+fn load_cpfsignore(base_path: &Path, ignore_patterns: &mut IgnorePatterns) {
+    let cpfsignore_path = base_path.join(CPFSIGNORE_FILE);
+    let contents = match fs::read_to_string(&cpfsignore_path) {
+        Ok(contents) => contents,
+        Err(_) => return,
+    };
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        ignore_patterns.add_pattern(line);
+    }
 }
 
 fn is_text_file(content: &[u8]) -> bool {
@@ -46,47 +78,373 @@ fn is_text_file(content: &[u8]) -> bool {
     )
 }
 
+/// Whether an ignore rule excludes a path or re-includes one previously excluded.
+///
+/// A pattern prefixed with `!` is a whitelist rule, borrowed from gitignore/watchexec
+/// semantics, letting users re-include files killed by an earlier (e.g. default) rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PatternType {
+    Ignore,
+    Whitelist,
+}
+
+/// One category of compiled rules (e.g. "matched against a basename" or "matched
+/// against a full relative path"), each entry carrying enough metadata to resolve
+/// gitignore-style "last matching rule wins" and directory-only restrictions.
+struct MatchSet {
+    // Literal patterns, keyed by the exact string they match, each key holding its
+    // rules in insertion order. A `HashMap` is the fast pre-check ripgrep-style
+    // tools use for exact rules; the last rule under a key that applies (a
+    // directory-only rule doesn't apply to a file) is this category's last match.
+    exact: HashMap<String, Vec<(PatternType, usize, bool)>>,
+    // `re:` and `glob:` patterns - rare enough relative to plain globs that a linear
+    // scan is fine; `glob:` is translated to a regex via `glob_to_regex` so `**` can
+    // cross directory separators the way a single compiled glob can't.
+    regexes: Vec<(Regex, PatternType, usize, bool)>,
+    // All other (unprefixed) glob patterns, compiled once into a single `GlobSet` so
+    // matching a path is one DFA pass instead of a loop over every pattern.
+    glob_builder: GlobSetBuilder,
+    glob_set: GlobSet,
+    // `matches()` on `glob_set` returns glob indices; this maps each index back to
+    // the rule's type, insertion order and directory-only flag.
+    glob_order: Vec<(PatternType, usize, bool)>,
+}
+
+impl MatchSet {
+    fn new() -> Self {
+        Self {
+            exact: HashMap::new(),
+            regexes: Vec::new(),
+            glob_builder: GlobSetBuilder::new(),
+            glob_set: GlobSet::empty(),
+            glob_order: Vec::new(),
+        }
+    }
+
+    fn add_exact(
+        &mut self,
+        key: String,
+        pattern_type: PatternType,
+        seq: usize,
+        directory_only: bool,
+    ) {
+        self.exact
+            .entry(key)
+            .or_default()
+            .push((pattern_type, seq, directory_only));
+    }
+
+    fn add_regex(
+        &mut self,
+        regex: Regex,
+        pattern_type: PatternType,
+        seq: usize,
+        directory_only: bool,
+    ) {
+        self.regexes
+            .push((regex, pattern_type, seq, directory_only));
+    }
+
+    fn add_glob(
+        &mut self,
+        glob_pattern: &str,
+        pattern_type: PatternType,
+        seq: usize,
+        directory_only: bool,
+    ) -> Result<(), globset::Error> {
+        let glob = GlobBuilder::new(glob_pattern)
+            .literal_separator(true)
+            .build()?;
+        self.glob_builder.add(glob);
+        self.glob_order.push((pattern_type, seq, directory_only));
+        Ok(())
+    }
+
+    fn finalize(&mut self) {
+        self.glob_set = match self.glob_builder.build() {
+            Ok(glob_set) => glob_set,
+            Err(e) => {
+                eprintln!("Failed to compile ignore patterns: {}", e);
+                GlobSet::empty()
+            }
+        };
+    }
+
+    /// Returns the type and insertion order of the last-inserted pattern in this
+    /// category that matches `s`, or `None` if no pattern matches. `is_directory`
+    /// filters out directory-only rules (trailing `/`) when `s` names a plain file.
+    fn best_match(&self, s: &str, is_directory: bool) -> Option<(usize, PatternType)> {
+        let mut best: Option<(usize, PatternType)> = self
+            .exact
+            .get(s)
+            .and_then(|rules| {
+                rules
+                    .iter()
+                    .rev()
+                    .find(|(_, _, directory_only)| is_directory || !directory_only)
+            })
+            .map(|&(pattern_type, seq, _)| (seq, pattern_type));
+
+        for &glob_index in &self.glob_set.matches(s) {
+            let (pattern_type, seq, directory_only) = self.glob_order[glob_index];
+            if directory_only && !is_directory {
+                continue;
+            }
+            if best.is_none_or(|(best_seq, _)| seq > best_seq) {
+                best = Some((seq, pattern_type));
+            }
+        }
+
+        for (regex, pattern_type, seq, directory_only) in &self.regexes {
+            if *directory_only && !is_directory {
+                continue;
+            }
+            if best.is_some_and(|(best_seq, _)| *seq <= best_seq) {
+                continue;
+            }
+            if regex.is_match(s) {
+                best = Some((*seq, *pattern_type));
+            }
+        }
+
+        best
+    }
+}
+
 struct IgnorePatterns {
-    exact_matches: HashSet<String>,
-    glob_patterns: Vec<Pattern>,
+    // Unanchored patterns (no `/`), matched against a path component's basename at
+    // any depth, as gitignore does.
+    basename: MatchSet,
+    // Anchored patterns (containing a `/`, or using `path:`), matched against the
+    // full relative path from the walk root.
+    path: MatchSet,
+    // Running insertion order, shared across both sets above so "last matching rule
+    // wins" holds globally, not just within one category.
+    seq: usize,
 }
 
 impl IgnorePatterns {
     fn new() -> Self {
         Self {
-            exact_matches: HashSet::new(),
-            glob_patterns: Vec::new(),
+            basename: MatchSet::new(),
+            path: MatchSet::new(),
+            seq: 0,
         }
     }
 
+    /// Compiles `pattern` and adds it to the rule set. A leading `!` marks the rule
+    /// as a whitelist. A trailing `/` restricts the rule to directories, and is
+    /// stripped before compiling. The remaining text is interpreted as:
+    /// - `glob:<pat>` - a shell glob, translated to a regex via [`glob_to_regex`] so
+    ///   `**` can cross directory separators the way a single glob alone can't.
+    /// - `re:<pat>` - a raw regular expression.
+    /// - `path:<pat>` - forced to match against the full relative path, even without
+    ///   a `/` in `<pat>`.
+    /// - anything else - the existing behavior: a glob if it contains `*`, `?` or
+    ///   `[`, otherwise an exact match.
+    ///
+    /// Apart from `path:` (always anchored), a pattern containing a `/` other than
+    /// the trailing directory-only one (a leading `/` included) is anchored to the
+    /// walk root and matched against the full relative path; one with no such `/`
+    /// matches a basename at any depth, matching gitignore semantics.
+    ///
+    /// Call [`IgnorePatterns::finalize`] once all patterns have been added, before
+    /// the first call to [`should_ignore_file`].
     fn add_pattern(&mut self, pattern: &str) {
-        // If the pattern contains glob characters, compile it as a glob pattern
-        if pattern.contains('*') || pattern.contains('?') || pattern.contains('[') {
-            match Pattern::new(pattern) {
-                Ok(glob_pattern) => self.glob_patterns.push(glob_pattern),
-                Err(e) => eprintln!("Invalid glob pattern '{}': {}", pattern, e),
+        let (pattern_type, without_bang) = match pattern.strip_prefix('!') {
+            Some(rest) => (PatternType::Whitelist, rest),
+            None => (PatternType::Ignore, pattern),
+        };
+
+        let seq = self.seq;
+        self.seq += 1;
+
+        if let Some(rest) = without_bang.strip_prefix("glob:") {
+            let (core, anchored, directory_only) = split_anchoring(rest);
+            let set = self.set_for(anchored);
+            set.add_regex(
+                regex_or_log(&glob_to_regex(&core), &core),
+                pattern_type,
+                seq,
+                directory_only,
+            );
+        } else if let Some(rest) = without_bang.strip_prefix("re:") {
+            let (core, anchored, directory_only) = split_anchoring(rest);
+            if let Ok(regex) =
+                Regex::new(&core).map_err(|e| eprintln!("Invalid regex pattern '{}': {}", core, e))
+            {
+                self.set_for(anchored)
+                    .add_regex(regex, pattern_type, seq, directory_only);
             }
+        } else if let Some(rest) = without_bang.strip_prefix("path:") {
+            let (core, _, directory_only) = split_anchoring(rest);
+            self.path.add_exact(core, pattern_type, seq, directory_only);
         } else {
-            // Otherwise, treat it as an exact match
-            self.exact_matches.insert(pattern.to_string());
+            let (core, anchored, directory_only) = split_anchoring(without_bang);
+            if core.contains('*') || core.contains('?') || core.contains('[') {
+                if let Err(e) =
+                    self.set_for(anchored)
+                        .add_glob(&core, pattern_type, seq, directory_only)
+                {
+                    eprintln!("Invalid glob pattern '{}': {}", core, e);
+                }
+            } else {
+                self.set_for(anchored)
+                    .add_exact(core, pattern_type, seq, directory_only);
+            }
         }
     }
 
-    fn should_ignore(&self, path_str: &str) -> bool {
-        // Check for exact matches
-        if self.exact_matches.contains(path_str) {
-            return true;
+    fn set_for(&mut self, anchored: bool) -> &mut MatchSet {
+        if anchored {
+            &mut self.path
+        } else {
+            &mut self.basename
         }
+    }
 
-        // Check against glob patterns
-        for pattern in &self.glob_patterns {
-            if pattern.matches(path_str) {
-                return true;
-            }
+    /// Builds the `GlobSet`s from every glob added so far. Must be called once all
+    /// patterns have been added and before matching begins.
+    fn finalize(&mut self) {
+        self.basename.finalize();
+        self.path.finalize();
+    }
+
+    /// Returns the type of the overall last-inserted pattern - considering both the
+    /// basename and the full relative path - that matches, or `None` if nothing
+    /// matches.
+    fn best_match(
+        &self,
+        name: &str,
+        relative_path: &str,
+        is_directory: bool,
+    ) -> Option<(usize, PatternType)> {
+        let basename_match = self.basename.best_match(name, is_directory);
+        let path_match = self.path.best_match(relative_path, is_directory);
+        match (basename_match, path_match) {
+            (Some(a), Some(b)) => Some(if a.0 >= b.0 { a } else { b }),
+            (a, None) => a,
+            (None, b) => b,
         }
+    }
+}
+
+/// Splits a (possibly slash-decorated) pattern body into its bare matchable text,
+/// whether it's anchored to the walk root, and whether it's restricted to
+/// directories - i.e. the gitignore rules for `/` (anywhere but the end = anchored)
+/// and a trailing `/` (directories only).
+fn split_anchoring(pattern: &str) -> (String, bool, bool) {
+    let directory_only = pattern.len() > 1 && pattern.ends_with('/');
+    let core = if directory_only {
+        &pattern[..pattern.len() - 1]
+    } else {
+        pattern
+    };
+    // Anchoring is decided on `core`, i.e. after the directory-only slash (if any)
+    // has been stripped - a trailing `/` alone (`build/`) only means "directories
+    // only", not "anchored to the root", matching gitignore's treatment of the two
+    // as independent flags.
+    let anchored = core.contains('/');
+    let core = core.strip_prefix('/').unwrap_or(core);
+    (core.to_string(), anchored, directory_only)
+}
+
+fn regex_or_log(source: &str, original: &str) -> Regex {
+    Regex::new(source).unwrap_or_else(|e| {
+        eprintln!("Invalid glob pattern '{}': {}", original, e);
+        // Never matches; keeps `add_pattern` infallible for the `glob:` case.
+        Regex::new("$^").unwrap()
+    })
+}
 
-        false
+/// Converts a shell glob to an equivalent regex source string, so `glob:` patterns
+/// and `re:` patterns can share the same [`Regex`]-based matcher. The caller is
+/// expected to have already stripped any anchoring `/` via [`split_anchoring`].
+fn glob_to_regex(pattern: &str) -> String {
+    format!("^{}$", glob_to_regex_fragment(pattern))
+}
+
+/// Does the actual char-by-char translation for [`glob_to_regex`], without the
+/// surrounding `^`/`$` anchors, so a `{a,b,c}` alternative can recurse into this
+/// directly and get the same `*`/`?`/`[...]`/escaping treatment as the top-level
+/// pattern, instead of being spliced into the output as live regex.
+fn glob_to_regex_fragment(pattern: &str) -> String {
+    let mut regex = String::new();
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                regex.push_str(".*");
+                i += 2;
+            }
+            '*' => {
+                regex.push_str("[^/]*");
+                i += 1;
+            }
+            '?' => {
+                regex.push_str("[^/]");
+                i += 1;
+            }
+            '[' => {
+                // Pass the character class through verbatim (including a leading `!`
+                // or `^` negation and a leading `]` meaning a literal `]`).
+                let mut j = i + 1;
+                if matches!(chars.get(j), Some('!') | Some('^')) {
+                    j += 1;
+                }
+                if chars.get(j) == Some(&']') {
+                    j += 1;
+                }
+                while j < chars.len() && chars[j] != ']' {
+                    j += 1;
+                }
+                if j < chars.len() {
+                    regex.push('[');
+                    if chars.get(i + 1) == Some(&'!') {
+                        regex.push('^');
+                        regex.extend(&chars[i + 2..=j]);
+                    } else {
+                        regex.extend(&chars[i + 1..=j]);
+                    }
+                    i = j + 1;
+                } else {
+                    regex.push_str("\\[");
+                    i += 1;
+                }
+            }
+            '{' => {
+                // Translate `{a,b,c}` into the regex alternation `(?:a|b|c)`, running
+                // each alternative back through this same translation so glob syntax
+                // and regex metacharacters inside it are handled identically to the
+                // rest of the pattern, rather than spliced in as live regex.
+                if let Some(end) = chars[i..].iter().position(|&c| c == '}').map(|p| p + i) {
+                    let alternatives: String = chars[i + 1..end].iter().collect();
+                    let alternatives: Vec<String> = alternatives
+                        .split(',')
+                        .map(glob_to_regex_fragment)
+                        .collect();
+                    regex.push_str("(?:");
+                    regex.push_str(&alternatives.join("|"));
+                    regex.push(')');
+                    i = end + 1;
+                } else {
+                    regex.push_str("\\{");
+                    i += 1;
+                }
+            }
+            c @ ('.' | '(' | ')' | '+' | '^' | '$' | '|' | '\\') => {
+                regex.push('\\');
+                regex.push(c);
+                i += 1;
+            }
+            c => {
+                regex.push(c);
+                i += 1;
+            }
+        }
     }
+    regex
 }
 
 fn should_ignore_file(
@@ -94,45 +452,63 @@ fn should_ignore_file(
     base_path: &std::path::Path,
     ignore_patterns: &IgnorePatterns,
 ) -> bool {
-    // Check if the file name matches any ignored pattern
-    if let Some(file_name) = path.file_name().and_then(|n| n.to_str()) {
-        if ignore_patterns.should_ignore(file_name) {
-            return true;
-        }
-    }
+    let relative_path = match path.strip_prefix(base_path) {
+        Ok(relative_path) => relative_path,
+        Err(_) => return false,
+    };
+
+    // Build the relative path with forward slashes regardless of platform, so
+    // anchored (`/`-containing) patterns behave identically on Windows and Unix.
+    let components: Vec<_> = relative_path
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
 
-    // Check if any parent directory matches ignored patterns
-    for ancestor in path.ancestors() {
-        if ancestor == base_path {
-            break;
+    // Walk ancestor directories first. Once a directory is ignored, files below it
+    // stay ignored even if a later rule would otherwise whitelist them directly,
+    // unless a whitelist rule matches the directory itself - you can't re-include a
+    // file whose parent directory is still excluded, matching gitignore behavior.
+    // That means once some ancestor's *own* verdict comes back Ignore, we stop: a
+    // deeper, unrelated directory's rule (even a whitelist) must not be allowed to
+    // override an ignore decided higher up the tree.
+    let mut ancestor_verdict: Option<(usize, PatternType)> = None;
+    let mut current_path = String::new();
+    for name in components.iter().take(components.len().saturating_sub(1)) {
+        if !current_path.is_empty() {
+            current_path.push('/');
         }
-        if let Some(dir_name) = ancestor.file_name().and_then(|n| n.to_str()) {
-            if ignore_patterns.should_ignore(dir_name) {
-                return true;
+        current_path.push_str(name);
+
+        if let Some(verdict) = ignore_patterns.best_match(name, &current_path, true) {
+            ancestor_verdict = Some(verdict);
+            if verdict.1 == PatternType::Ignore {
+                break;
             }
         }
     }
 
-    // Check if the relative path matches any ignored pattern
-    if let Ok(relative_path) = path.strip_prefix(base_path) {
-        let relative_path_str = relative_path.to_string_lossy();
-        if ignore_patterns.should_ignore(&relative_path_str) {
-            return true;
+    if matches!(ancestor_verdict, Some((_, PatternType::Ignore))) {
+        return true;
+    }
+
+    // The path isn't blocked by an ignored ancestor, so resolve the file itself -
+    // which, being a file and not a directory, can't match a directory-only rule.
+    let mut verdict = ancestor_verdict;
+    if let Some(file_name) = components.last() {
+        if !current_path.is_empty() {
+            current_path.push('/');
         }
+        current_path.push_str(file_name);
 
-        // Also check path components for glob matches
-        let mut current = PathBuf::new();
-        let components: VecDeque<_> = relative_path.components().collect();
-        for component in components {
-            current.push(component);
-            let current_str = current.to_string_lossy();
-            if ignore_patterns.should_ignore(&current_str) {
-                return true;
-            }
+        if let Some(file_verdict) = ignore_patterns
+            .best_match(file_name, &current_path, false)
+            .filter(|file_verdict| verdict.is_none_or(|(seq, _)| file_verdict.0 > seq))
+        {
+            verdict = Some(file_verdict);
         }
     }
 
-    false
+    matches!(verdict, Some((_, PatternType::Ignore)))
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -142,20 +518,33 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Set up ignore patterns
     let mut ignore_patterns = IgnorePatterns::new();
 
-    // Add default ignored files
-    for &file in IGNORED_FILES {
-        ignore_patterns.add_pattern(file);
+    // Add default ignored files, unless the user opted out
+    if !args.no_default_ignore {
+        for &file in IGNORED_FILES {
+            ignore_patterns.add_pattern(file);
+        }
     }
 
+    // Load the project-local .cpfsignore before CLI patterns, so --ignore can still
+    // override it with a whitelist rule
+    load_cpfsignore(&args.path, &mut ignore_patterns);
+
     // Add user-provided ignored files
     for pattern in &args.ignore {
         ignore_patterns.add_pattern(pattern);
     }
 
+    ignore_patterns.finalize();
+
     let mut files = HashSet::new();
 
-    // Walk through directory respecting gitignore
-    for entry in Walk::new(&args.path) {
+    // Walk through directory, respecting .gitignore unless --no-ignore was passed
+    let walker = WalkBuilder::new(&args.path)
+        .git_ignore(!args.no_ignore)
+        .git_global(!args.no_ignore)
+        .build();
+
+    for entry in walker {
         let entry = match entry {
             Ok(entry) => entry,
             Err(err) => {
@@ -165,7 +554,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         };
 
         // Skip if it's not a file
-        if !entry.file_type().map_or(false, |ft| ft.is_file()) {
+        if !entry.file_type().is_some_and(|ft| ft.is_file()) {
             continue;
         }
 
@@ -223,3 +612,82 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(rules: &[&str]) -> IgnorePatterns {
+        let mut ignore_patterns = IgnorePatterns::new();
+        for rule in rules {
+            ignore_patterns.add_pattern(rule);
+        }
+        ignore_patterns.finalize();
+        ignore_patterns
+    }
+
+    fn ignored(rules: &[&str], path: &str) -> bool {
+        let ignore_patterns = patterns(rules);
+        should_ignore_file(
+            Path::new("/base").join(path).as_path(),
+            Path::new("/base"),
+            &ignore_patterns,
+        )
+    }
+
+    #[test]
+    fn whitelist_reincludes_a_file_killed_by_an_earlier_ignore_rule() {
+        assert!(ignored(&["*.log"], "app.log"));
+        assert!(!ignored(&["*.log", "!app.log"], "app.log"));
+    }
+
+    #[test]
+    fn whitelist_cannot_reinclude_a_file_whose_parent_directory_is_still_ignored() {
+        // `dist` is ignored as a whole, so whitelisting one file under it doesn't
+        // help: the directory itself is never re-included.
+        assert!(ignored(&["dist", "!dist/manifest.json"], "dist/manifest.json"));
+    }
+
+    #[test]
+    fn whitelist_on_a_deeper_unrelated_ancestor_cannot_undo_a_shallower_ignore() {
+        // `a` is hard-ignored and never itself re-included; `!c` only whitelists a
+        // directory named `c`, which is a different (deeper) ancestor, so it must
+        // not pull `a/c/file.txt` back out of the ignored `a` tree.
+        assert!(ignored(&["a", "!c"], "a/c/file.txt"));
+    }
+
+    #[test]
+    fn later_rule_wins_regardless_of_category() {
+        // A later plain ignore rule can itself be undone by an even later whitelist.
+        assert!(!ignored(&["!*.log", "*.log", "!app.log"], "app.log"));
+        assert!(ignored(&["!app.log", "*.log"], "app.log"));
+    }
+
+    #[test]
+    fn trailing_slash_only_pattern_matches_directories_at_any_depth() {
+        // `node_modules/` is directory-only, but NOT root-anchored: it must match
+        // `node_modules` wherever it occurs, not just at the walk root.
+        assert!(ignored(&["node_modules/"], "node_modules/a.txt"));
+        assert!(ignored(
+            &["node_modules/"],
+            "packages/foo/node_modules/b.txt"
+        ));
+    }
+
+    #[test]
+    fn leading_slash_anchors_to_the_walk_root_only() {
+        assert!(ignored(&["/build"], "build/out.txt"));
+        assert!(!ignored(&["/build"], "packages/foo/build/out.txt"));
+    }
+
+    #[test]
+    fn directory_only_rule_does_not_match_a_same_named_file() {
+        assert!(!ignored(&["build/"], "build"));
+        assert!(ignored(&["build/"], "build/out.txt"));
+    }
+
+    #[test]
+    fn unanchored_pattern_matches_basename_at_any_depth() {
+        assert!(ignored(&["*.log"], "a/b/app.log"));
+    }
+}